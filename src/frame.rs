@@ -0,0 +1,108 @@
+//! Self-describing, length-delimited framing on top of `Buffer`.
+//!
+//! Frames are PNG-signature-inspired: an 8-byte magic signature (to catch stream desync or
+//! truncated transfers), a 1-byte format version, a big-endian `u32` payload length, and then
+//! the payload itself.
+
+/// Magic signature prefixed to every frame. The non-ASCII first byte and the embedded `\r\n`
+/// pair make truncated transfers and line-ending-mangled transports easy to detect, mirroring
+/// the PNG file signature's rationale.
+const MAGIC: [u8; 8] = [0x89, b'S', b'B', b'U', b'F', b'\r', b'\n', 0x1a];
+/// Current wire-format version written into each frame.
+const FRAME_VERSION: u8 = 1;
+/// Length of the frame header: magic + version byte + `u32` length.
+const HEADER_LEN: usize = MAGIC.len() + 1 + 4;
+
+/// Errors returned while decoding a framed message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FrameError {
+    /// The magic signature did not match; the stream is desynchronized or corrupt.
+    BadMagic,
+    /// The frame's version byte is not one this crate understands.
+    UnsupportedVersion(u8),
+}
+
+impl core::fmt::Display for FrameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FrameError::BadMagic => write!(f, "frame magic signature mismatch"),
+            FrameError::UnsupportedVersion(v) => write!(f, "unsupported frame version {v}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FrameError {}
+
+impl crate::Buffer {
+    /// Appends `payload` as a self-describing frame: magic, version, big-endian length prefix,
+    /// then the payload.
+    pub fn write_frame(&mut self, payload: &[u8]) {
+        self.append(&MAGIC);
+        self.push(FRAME_VERSION);
+        self.append(&(payload.len() as u32).to_be_bytes());
+        self.append(payload);
+    }
+
+    /// Reads one frame starting at the current read position.
+    ///
+    /// Returns `Ok(None)` if the buffer does not yet hold a complete frame, signaling "need
+    /// more bytes"; the read marker only advances past the frame once it is fully available.
+    pub fn read_frame(&mut self) -> Result<Option<&[u8]>, FrameError> {
+        let available = self.write - self.read;
+        if available < HEADER_LEN {
+            return Ok(None);
+        }
+        let start = self.read;
+        if self.data[start..start + MAGIC.len()] != MAGIC {
+            return Err(FrameError::BadMagic);
+        }
+        let version = self.data[start + MAGIC.len()];
+        if version != FRAME_VERSION {
+            return Err(FrameError::UnsupportedVersion(version));
+        }
+        let len_offset = start + MAGIC.len() + 1;
+        let len = u32::from_be_bytes(
+            self.data[len_offset..len_offset + 4]
+                .try_into()
+                .expect("slice is exactly 4 bytes"),
+        ) as usize;
+        let payload_start = len_offset + 4;
+        if available < HEADER_LEN + len {
+            return Ok(None);
+        }
+        self.read = payload_start + len;
+        Ok(Some(&self.data[payload_start..payload_start + len]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrameError;
+    use crate::Buffer;
+
+    #[test]
+    fn write_and_read_frame_roundtrip() {
+        let mut buffer = Buffer::new();
+        buffer.write_frame(b"hello");
+        buffer.write_frame(b"world");
+        assert_eq!(buffer.read_frame().unwrap(), Some(b"hello".as_slice()));
+        assert_eq!(buffer.read_frame().unwrap(), Some(b"world".as_slice()));
+        assert_eq!(buffer.read_frame().unwrap(), None);
+    }
+
+    #[test]
+    fn read_frame_needs_more_bytes() {
+        let mut buffer = Buffer::new();
+        buffer.write_frame(b"hello");
+        buffer.write -= 1;
+        assert_eq!(buffer.read_frame().unwrap(), None);
+    }
+
+    #[test]
+    fn read_frame_rejects_bad_magic() {
+        let mut buffer = Buffer::new();
+        buffer.append(&[0u8; 13]);
+        assert_eq!(buffer.read_frame(), Err(FrameError::BadMagic));
+    }
+}
@@ -0,0 +1,98 @@
+//! `no_std`-friendly `Read`/`Write`/`BufRead` traits modeled on the `embedded-io` crate.
+//!
+//! These mirror the `std::io` traits gated behind the `std` feature, but route errors through
+//! an associated `Error` type instead of `std::io::Error` so the surface stays usable on
+//! bare-metal/embedded targets. `Buffer`'s own implementations can never fail, so they use
+//! `core::convert::Infallible` as their error type.
+
+use core::convert::Infallible;
+
+/// Associates an error type with a `Read`/`Write`/`BufRead` implementation.
+pub trait ErrorType {
+    /// The error type returned by this trait's methods.
+    type Error: core::fmt::Debug;
+}
+
+/// A `no_std` counterpart to `std::io::Read`.
+pub trait Read: ErrorType {
+    /// Reads some bytes into `buf`, returning the number of bytes read.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error>;
+
+    /// Reads exactly `buf.len()` bytes, looping on `read` until `buf` is full.
+    ///
+    /// Returns `UnexpectedEof` if the underlying reader returns `0` bytes before `buf` is
+    /// filled.
+    fn read_exact(&mut self, mut buf: &mut [u8]) -> Result<(), ReadExactError<Self::Error>> {
+        while !buf.is_empty() {
+            match self.read(buf) {
+                Ok(0) => return Err(ReadExactError::UnexpectedEof),
+                Ok(n) => buf = &mut buf[n..],
+                Err(e) => return Err(ReadExactError::Other(e)),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by the default `Read::read_exact` implementation.
+#[derive(Debug)]
+pub enum ReadExactError<E> {
+    /// The underlying reader returned `0` bytes before `buf` was completely filled.
+    UnexpectedEof,
+    /// The underlying reader returned an error.
+    Other(E),
+}
+
+/// A `no_std` counterpart to `std::io::Write`.
+pub trait Write: ErrorType {
+    /// Writes some bytes from `buf`, returning the number of bytes written.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error>;
+
+    /// Flushes any buffered data.
+    fn flush(&mut self) -> Result<(), Self::Error>;
+}
+
+/// A `no_std` counterpart to `std::io::BufRead`.
+pub trait BufRead: ErrorType {
+    /// Returns the currently available, unconsumed bytes without advancing past them.
+    fn fill_buf(&mut self) -> Result<&[u8], Self::Error>;
+
+    /// Marks `amt` bytes as consumed, advancing past the region previously returned by
+    /// `fill_buf`.
+    fn consume(&mut self, amt: usize);
+}
+
+impl ErrorType for crate::Buffer {
+    type Error = Infallible;
+}
+
+impl Read for crate::Buffer {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let alen = self.write - self.read;
+        let rlen = core::cmp::min(alen, buf.len());
+        buf[..rlen].copy_from_slice(&self.data[self.read..self.read + rlen]);
+        self.read += rlen;
+        Ok(rlen)
+    }
+}
+
+impl Write for crate::Buffer {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.append(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl BufRead for crate::Buffer {
+    fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        Ok(&self.data[self.read..self.write])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.read = core::cmp::min(self.read + amt, self.write);
+    }
+}
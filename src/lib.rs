@@ -7,19 +7,56 @@
 //!
 //! ```
 //! use simbuf::Buffer;
-//! use std::io::Write;
+//! use simbuf::io::Write;
 //!
 //! let mut buffer = Buffer::new();
 //! buffer.write(b"Hello, world!").unwrap();
 //!
 //! assert_eq!(AsRef::<[u8]>::as_ref(&buffer), b"Hello, world!");
 //! ```
+//!
+//! # `no_std`
+//!
+//! Without the default `std` feature this crate builds on `core` and `alloc` alone, so
+//! `Buffer` can be used on bare-metal/embedded targets through the `io` module's
+//! `embedded-io`-style traits.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+pub mod frame;
+pub mod io;
 
 /// The default initial size of the buffer in bytes.
 const INITIAL_SIZE: usize = 8192;
 /// The bin size of allocations in case the buffer is too small.
 const ALLOC_SIZE: usize = 2048;
 
+/// Error returned when decoding a LEB128 varint fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VarintError {
+    /// More than 10 bytes were consumed without the varint terminating.
+    TooLong,
+    /// The buffer ran out of bytes before the varint terminated.
+    UnexpectedEof,
+}
+
+impl core::fmt::Display for VarintError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VarintError::TooLong => write!(f, "varint exceeds the maximum of 10 bytes"),
+            VarintError::UnexpectedEof => write!(f, "buffer exhausted while decoding a varint"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VarintError {}
+
 /// The buffer with internal data storage, read marker and write marker.
 ///
 /// TODO
@@ -92,10 +129,27 @@ impl Buffer {
         self.write += data.len();
     }
 
-    /// Tries to move the read marker position forward by `seek` positions until it reaches the
-    /// write marker position.
-    pub fn seek(&mut self, seek: usize) {
-        self.read += core::cmp::min(self.read + seek, self.write);
+    /// Moves the read marker forward by `n` positions, clamped to the write marker position.
+    ///
+    /// Named distinctly from `std::io::Seek::seek` (below), which takes a `SeekFrom` and can
+    /// also move the read marker backward.
+    pub fn advance_read(&mut self, n: usize) {
+        self.read = core::cmp::min(self.read + n, self.write);
+    }
+
+    /// Returns the current read-marker position.
+    pub fn position(&self) -> usize {
+        self.read
+    }
+
+    /// Sets the read-marker position, clamping to the write marker.
+    pub fn set_position(&mut self, pos: usize) {
+        self.read = core::cmp::min(pos, self.write);
+    }
+
+    /// Resets the read marker back to the start of the buffer.
+    pub fn rewind(&mut self) {
+        self.read = 0;
     }
 
     /// TODO
@@ -104,6 +158,41 @@ impl Buffer {
         self.write = 0;
     }
 
+    /// Moves the unread region `self.data[self.read..self.write]` to the front of the buffer,
+    /// without reallocating, reclaiming the space consumed by bytes already read.
+    ///
+    /// This invalidates any position previously saved via `position()`: every byte shifts back
+    /// by `self.read` places. `compact` is never called implicitly by plain writes (`append`,
+    /// `push`, the codec methods, ...) — only `reserve` opts into it — so a cursor obtained from
+    /// `position()` stays valid across ordinary writes.
+    pub fn compact(&mut self) {
+        if self.read == 0 {
+            return;
+        }
+        let live = self.write - self.read;
+        self.data.copy_within(self.read..self.write, 0);
+        self.read = 0;
+        self.write = live;
+    }
+
+    /// Returns the total number of bytes currently allocated.
+    pub fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Grows the buffer's allocation so that at least `additional` more bytes can be written
+    /// without a further reallocation, compacting first if the already-read prefix alone would
+    /// make room.
+    ///
+    /// Unlike plain writes, this may call `compact()` and therefore invalidate a previously
+    /// saved `position()` — only call it when no such position needs to survive.
+    pub fn reserve(&mut self, additional: usize) {
+        if self.data.len() < self.write + additional && self.read >= additional {
+            self.compact();
+        }
+        self.check_allocation(additional);
+    }
+
     /// Returns the number of elements in the buffer, also referred to as its write position.
     ///
     /// # Example
@@ -150,6 +239,193 @@ impl Buffer {
         }
     }
 
+    /// Writes `v` as a big-endian `u16`, advancing the write marker.
+    pub fn write_u16_be(&mut self, v: u16) {
+        self.append(&v.to_be_bytes());
+    }
+
+    /// Writes `v` as a little-endian `u16`, advancing the write marker.
+    pub fn write_u16_le(&mut self, v: u16) {
+        self.append(&v.to_le_bytes());
+    }
+
+    /// Writes `v` as a big-endian `u32`, advancing the write marker.
+    pub fn write_u32_be(&mut self, v: u32) {
+        self.append(&v.to_be_bytes());
+    }
+
+    /// Writes `v` as a little-endian `u32`, advancing the write marker.
+    pub fn write_u32_le(&mut self, v: u32) {
+        self.append(&v.to_le_bytes());
+    }
+
+    /// Writes `v` as a big-endian `u64`, advancing the write marker.
+    pub fn write_u64_be(&mut self, v: u64) {
+        self.append(&v.to_be_bytes());
+    }
+
+    /// Writes `v` as a little-endian `u64`, advancing the write marker.
+    pub fn write_u64_le(&mut self, v: u64) {
+        self.append(&v.to_le_bytes());
+    }
+
+    /// Writes `v` as a big-endian `i16`, advancing the write marker.
+    pub fn write_i16_be(&mut self, v: i16) {
+        self.append(&v.to_be_bytes());
+    }
+
+    /// Writes `v` as a little-endian `i16`, advancing the write marker.
+    pub fn write_i16_le(&mut self, v: i16) {
+        self.append(&v.to_le_bytes());
+    }
+
+    /// Writes `v` as a big-endian `i32`, advancing the write marker.
+    pub fn write_i32_be(&mut self, v: i32) {
+        self.append(&v.to_be_bytes());
+    }
+
+    /// Writes `v` as a little-endian `i32`, advancing the write marker.
+    pub fn write_i32_le(&mut self, v: i32) {
+        self.append(&v.to_le_bytes());
+    }
+
+    /// Writes `v` as a big-endian `i64`, advancing the write marker.
+    pub fn write_i64_be(&mut self, v: i64) {
+        self.append(&v.to_be_bytes());
+    }
+
+    /// Writes `v` as a little-endian `i64`, advancing the write marker.
+    pub fn write_i64_le(&mut self, v: i64) {
+        self.append(&v.to_le_bytes());
+    }
+
+    /// Reads a big-endian `u16` from the current read position, advancing past it. Returns
+    /// `None` if fewer than 2 bytes are available.
+    pub fn read_u16_be(&mut self) -> Option<u16> {
+        self.read_fixed().map(u16::from_be_bytes)
+    }
+
+    /// Reads a little-endian `u16` from the current read position, advancing past it. Returns
+    /// `None` if fewer than 2 bytes are available.
+    pub fn read_u16_le(&mut self) -> Option<u16> {
+        self.read_fixed().map(u16::from_le_bytes)
+    }
+
+    /// Reads a big-endian `u32` from the current read position, advancing past it. Returns
+    /// `None` if fewer than 4 bytes are available.
+    pub fn read_u32_be(&mut self) -> Option<u32> {
+        self.read_fixed().map(u32::from_be_bytes)
+    }
+
+    /// Reads a little-endian `u32` from the current read position, advancing past it. Returns
+    /// `None` if fewer than 4 bytes are available.
+    pub fn read_u32_le(&mut self) -> Option<u32> {
+        self.read_fixed().map(u32::from_le_bytes)
+    }
+
+    /// Reads a big-endian `u64` from the current read position, advancing past it. Returns
+    /// `None` if fewer than 8 bytes are available.
+    pub fn read_u64_be(&mut self) -> Option<u64> {
+        self.read_fixed().map(u64::from_be_bytes)
+    }
+
+    /// Reads a little-endian `u64` from the current read position, advancing past it. Returns
+    /// `None` if fewer than 8 bytes are available.
+    pub fn read_u64_le(&mut self) -> Option<u64> {
+        self.read_fixed().map(u64::from_le_bytes)
+    }
+
+    /// Reads a big-endian `i16` from the current read position, advancing past it. Returns
+    /// `None` if fewer than 2 bytes are available.
+    pub fn read_i16_be(&mut self) -> Option<i16> {
+        self.read_fixed().map(i16::from_be_bytes)
+    }
+
+    /// Reads a little-endian `i16` from the current read position, advancing past it. Returns
+    /// `None` if fewer than 2 bytes are available.
+    pub fn read_i16_le(&mut self) -> Option<i16> {
+        self.read_fixed().map(i16::from_le_bytes)
+    }
+
+    /// Reads a big-endian `i32` from the current read position, advancing past it. Returns
+    /// `None` if fewer than 4 bytes are available.
+    pub fn read_i32_be(&mut self) -> Option<i32> {
+        self.read_fixed().map(i32::from_be_bytes)
+    }
+
+    /// Reads a little-endian `i32` from the current read position, advancing past it. Returns
+    /// `None` if fewer than 4 bytes are available.
+    pub fn read_i32_le(&mut self) -> Option<i32> {
+        self.read_fixed().map(i32::from_le_bytes)
+    }
+
+    /// Reads a big-endian `i64` from the current read position, advancing past it. Returns
+    /// `None` if fewer than 8 bytes are available.
+    pub fn read_i64_be(&mut self) -> Option<i64> {
+        self.read_fixed().map(i64::from_be_bytes)
+    }
+
+    /// Reads a little-endian `i64` from the current read position, advancing past it. Returns
+    /// `None` if fewer than 8 bytes are available.
+    pub fn read_i64_le(&mut self) -> Option<i64> {
+        self.read_fixed().map(i64::from_le_bytes)
+    }
+
+    /// Appends `v` as an unsigned LEB128 varint (at most 10 bytes for a `u64`), low 7-bit group
+    /// first, advancing the write marker.
+    pub fn write_varint_u64(&mut self, mut v: u64) {
+        loop {
+            let byte = (v & 0x7f) as u8;
+            v >>= 7;
+            if v == 0 {
+                self.push(byte);
+                break;
+            }
+            self.push(byte | 0x80);
+        }
+    }
+
+    /// Reads an unsigned LEB128 varint, advancing the read marker past it.
+    pub fn read_varint_u64(&mut self) -> Result<u64, VarintError> {
+        let mut result: u64 = 0;
+        for i in 0..10 {
+            if self.read >= self.write {
+                return Err(VarintError::UnexpectedEof);
+            }
+            let byte = self.data[self.read];
+            self.read += 1;
+            result |= ((byte & 0x7f) as u64) << (7 * i);
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+        Err(VarintError::TooLong)
+    }
+
+    /// Appends `v` as a zig-zag-encoded signed LEB128 varint, advancing the write marker.
+    pub fn write_varint_i64(&mut self, v: i64) {
+        self.write_varint_u64(((v << 1) ^ (v >> 63)) as u64);
+    }
+
+    /// Reads a zig-zag-encoded signed LEB128 varint, advancing the read marker past it.
+    pub fn read_varint_i64(&mut self) -> Result<i64, VarintError> {
+        let u = self.read_varint_u64()?;
+        Ok(((u >> 1) as i64) ^ -((u & 1) as i64))
+    }
+
+    /// Reads a fixed-size `N`-byte array from the current read position, advancing past it.
+    /// Returns `None` if fewer than `N` bytes are available.
+    #[inline]
+    fn read_fixed<const N: usize>(&mut self) -> Option<[u8; N]> {
+        if self.write - self.read < N {
+            return None;
+        }
+        let mut buf = [0u8; N];
+        buf.copy_from_slice(&self.data[self.read..self.read + N]);
+        self.read += N;
+        Some(buf)
+    }
+
     #[inline]
     fn from_slice_int(src: &[u8]) -> Self {
         let write = src.len();
@@ -161,6 +437,12 @@ impl Buffer {
     }
 
     #[inline]
+    /// Extends the allocation, if needed, so `dlen` more bytes can be written.
+    ///
+    /// This never compacts: it's called from every plain write path (`append`, `push`, the
+    /// codec methods, `BufMut::chunk_mut`, ...), and compacting there would silently shift
+    /// already-read bytes and invalidate any position saved via `position()`. `reserve()` is
+    /// the opt-in path for reclaiming read bytes via `compact()`.
     fn check_allocation(&mut self, dlen: usize) {
         if self.data.len() < self.write + dlen {
             let nalloc = if dlen < ALLOC_SIZE {
@@ -172,7 +454,22 @@ impl Buffer {
         }
     }
 
-    #[cfg(feature = "tokio")]
+    /// Appends many discontiguous slices in a single allocation check and a single write-marker
+    /// update, instead of one `append` call (and capacity check) per slice.
+    #[cfg(feature = "std")]
+    pub fn append_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) {
+        let total: usize = bufs.iter().map(|b| b.len()).sum();
+        self.check_allocation(total);
+        let mut pos = self.write;
+        for buf in bufs {
+            let len = buf.len();
+            self.data[pos..pos + len].copy_from_slice(buf);
+            pos += len;
+        }
+        self.write = pos;
+    }
+
+    #[cfg(all(feature = "tokio", feature = "std"))]
     pub async fn read_from_async<S>(&mut self, source: &mut S) -> std::io::Result<usize>
     where
         S: tokio::io::AsyncReadExt + core::marker::Unpin,
@@ -182,7 +479,7 @@ impl Buffer {
         Ok(n_bytes)
     }
 
-    #[cfg(feature = "tokio")]
+    #[cfg(all(feature = "tokio", feature = "std"))]
     pub async fn write_to_async<S>(&mut self, sink: &mut S) -> std::io::Result<usize>
     where
         S: tokio::io::AsyncWriteExt + core::marker::Unpin,
@@ -193,6 +490,33 @@ impl Buffer {
         self.read = self.write;
         Ok(n_bytes)
     }
+
+    /// Reads from `source` into the write-available region.
+    ///
+    /// Named and shaped to pair with `write_vectored_to_async` below, but `tokio::io::AsyncRead`
+    /// has no vectored-read counterpart to `poll_write_vectored`, so this currently behaves like
+    /// `read_from_async`.
+    #[cfg(all(feature = "tokio", feature = "std"))]
+    pub async fn read_vectored_from_async<S>(&mut self, source: &mut S) -> std::io::Result<usize>
+    where
+        S: tokio::io::AsyncReadExt + core::marker::Unpin,
+    {
+        self.read_from_async(source).await
+    }
+
+    /// Writes the unread region to `sink` with one single-element vectored write call and
+    /// advances the read marker by the number of bytes written, which may be fewer than the
+    /// unread region holds.
+    #[cfg(all(feature = "tokio", feature = "std"))]
+    pub async fn write_vectored_to_async<S>(&mut self, sink: &mut S) -> std::io::Result<usize>
+    where
+        S: tokio::io::AsyncWriteExt + core::marker::Unpin,
+    {
+        let bufs = [std::io::IoSlice::new(&self.data[self.read..self.write])];
+        let n_bytes = sink.write_vectored(&bufs).await?;
+        self.read += n_bytes;
+        Ok(n_bytes)
+    }
 }
 
 impl core::fmt::Debug for Buffer {
@@ -282,6 +606,14 @@ impl std::io::Write for Buffer {
     fn flush(&mut self) -> std::result::Result<(), std::io::Error> {
         Ok(())
     }
+
+    fn write_vectored(
+        &mut self,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> std::result::Result<usize, std::io::Error> {
+        self.append_vectored(bufs);
+        Ok(bufs.iter().map(|b| b.len()).sum())
+    }
 }
 
 #[cfg(feature = "std")]
@@ -294,9 +626,78 @@ impl std::io::Read for Buffer {
     }
 }
 
+#[cfg(feature = "std")]
+impl std::io::Seek for Buffer {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        // Mirrors `std::io::Cursor::seek`: `Start` is always non-negative so it can't overflow,
+        // but `Current`/`End` combine a base with a signed offset and must use checked
+        // arithmetic instead of plain `as`/`+`, which can panic on overflow or silently wrap.
+        let (base, offset) = match pos {
+            std::io::SeekFrom::Start(n) => {
+                self.read = core::cmp::min(n, self.write as u64) as usize;
+                return Ok(self.read as u64);
+            }
+            std::io::SeekFrom::Current(n) => (self.read as u64, n),
+            std::io::SeekFrom::End(n) => (self.write as u64, n),
+        };
+        let new_pos = if offset >= 0 {
+            base.checked_add(offset as u64)
+        } else {
+            base.checked_sub(offset.unsigned_abs())
+        };
+        match new_pos {
+            Some(n) => {
+                self.read = core::cmp::min(n, self.write as u64) as usize;
+                Ok(self.read as u64)
+            }
+            None => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl bytes::Buf for Buffer {
+    fn remaining(&self) -> usize {
+        self.write - self.read
+    }
+
+    fn chunk(&self) -> &[u8] {
+        &self.data[self.read..self.write]
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        assert!(
+            cnt <= bytes::Buf::remaining(self),
+            "cannot advance past the write marker"
+        );
+        self.read += cnt;
+    }
+}
+
+#[cfg(feature = "bytes")]
+unsafe impl bytes::BufMut for Buffer {
+    fn remaining_mut(&self) -> usize {
+        self.data.len() - self.write
+    }
+
+    fn chunk_mut(&mut self) -> &mut bytes::buf::UninitSlice {
+        self.check_allocation(1);
+        bytes::buf::UninitSlice::new(&mut self.data[self.write..])
+    }
+
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        self.write += cnt;
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Buffer;
+    use super::{Buffer, VarintError};
+    use crate::vec;
+    #[cfg(feature = "std")]
     use std::io::{Read, Write};
 
     const REFDATA: [u8; 5] = [1, 2, 4, 8, 16];
@@ -322,10 +723,10 @@ mod tests {
     }
 
     #[test]
-    fn as_ref_and_seek() {
+    fn as_ref_and_advance_read() {
         let mut buffer = Buffer::from("Hello, world!".as_bytes());
         assert_eq!(buffer.as_ref(), "Hello, world!".as_bytes());
-        buffer.seek(7);
+        buffer.advance_read(7);
         assert_eq!(buffer.as_ref(), "world!".as_bytes());
     }
 
@@ -345,4 +746,162 @@ mod tests {
         buffer.read(&mut vbuf).unwrap();
         assert_eq!(&vbuf[..5], REFDATA.as_slice());
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_io_seek_cursor() {
+        use std::io::{Seek, SeekFrom};
+
+        let mut buffer = Buffer::from("Hello, world!".as_bytes());
+        assert_eq!(Seek::seek(&mut buffer, SeekFrom::Start(7)).unwrap(), 7);
+        assert_eq!(buffer.as_ref(), "world!".as_bytes());
+        assert_eq!(Seek::seek(&mut buffer, SeekFrom::Current(-2)).unwrap(), 5);
+        assert_eq!(
+            Seek::seek(&mut buffer, SeekFrom::End(0)).unwrap(),
+            buffer.len() as u64
+        );
+        buffer.rewind();
+        assert_eq!(buffer.position(), 0);
+        buffer.set_position(1000);
+        assert_eq!(buffer.position(), buffer.len());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_io_seek_does_not_panic_or_wrap_on_overflow() {
+        use std::io::{Seek, SeekFrom};
+
+        let mut buffer = Buffer::from("Hello, world!".as_bytes());
+
+        // Must not panic with "attempt to add with overflow".
+        let pos = Seek::seek(&mut buffer, SeekFrom::Current(i64::MAX)).unwrap();
+        assert_eq!(pos, buffer.len() as u64);
+
+        // Must not wrap the u64 -> i64 cast into a negative offset that clamps to 0.
+        let pos = Seek::seek(&mut buffer, SeekFrom::Start(u64::MAX)).unwrap();
+        assert_eq!(pos, buffer.len() as u64);
+
+        // A genuinely overflowing seek reports an error instead of panicking or wrapping.
+        buffer.rewind();
+        let err = Seek::seek(&mut buffer, SeekFrom::Current(-1)).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn append_vectored_concatenates_slices() {
+        let header = b"head:";
+        let payload = b"payload";
+        let trailer = b"!";
+        let mut buffer = Buffer::new();
+        buffer.append_vectored(&[
+            std::io::IoSlice::new(header),
+            std::io::IoSlice::new(payload),
+            std::io::IoSlice::new(trailer),
+        ]);
+        assert_eq!(buffer.as_ref(), b"head:payload!".as_slice());
+    }
+
+    #[test]
+    fn position_survives_allocation_triggering_write() {
+        let mut buffer = Buffer::new();
+        let cap = buffer.capacity();
+        buffer.append(&vec![0xAA; cap]);
+        buffer.advance_read(100);
+        let mark = buffer.position();
+        let byte_at_mark = buffer.data[mark];
+
+        buffer.push(0xFF);
+
+        assert!(
+            buffer.capacity() > cap,
+            "push should have grown the allocation instead of compacting"
+        );
+        assert_eq!(buffer.position(), mark);
+        assert_eq!(buffer.data[mark], byte_at_mark);
+    }
+
+    #[test]
+    fn compact_reclaims_read_bytes() {
+        let mut buffer = Buffer::from(REFDATA);
+        buffer.advance_read(3);
+        let cap_before = buffer.capacity();
+        buffer.compact();
+        assert_eq!(buffer.read, 0);
+        assert_eq!(&buffer, &REFDATA[3..]);
+        assert_eq!(buffer.capacity(), cap_before);
+    }
+
+    #[test]
+    fn embedded_io_read_exact() {
+        use crate::io::Read;
+
+        let mut buffer = Buffer::from(REFDATA);
+        let mut vbuf = [0u8; 5];
+        Read::read_exact(&mut buffer, &mut vbuf).unwrap();
+        assert_eq!(vbuf, REFDATA);
+
+        let mut too_much = [0u8; 1];
+        assert!(matches!(
+            Read::read_exact(&mut buffer, &mut too_much),
+            Err(crate::io::ReadExactError::UnexpectedEof)
+        ));
+    }
+
+    #[test]
+    fn fixed_width_integers_roundtrip() {
+        let mut buffer = Buffer::new();
+        buffer.write_u32_be(0x0102_0304);
+        buffer.write_u16_le(0xabcd);
+        buffer.write_i64_be(-1);
+        assert_eq!(buffer.read_u32_be(), Some(0x0102_0304));
+        assert_eq!(buffer.read_u16_le(), Some(0xabcd));
+        assert_eq!(buffer.read_i64_be(), Some(-1));
+        assert_eq!(buffer.read_u16_be(), None);
+    }
+
+    #[test]
+    fn varints_roundtrip() {
+        let mut buffer = Buffer::new();
+        for v in [0u64, 1, 127, 128, 300, u64::MAX] {
+            buffer.write_varint_u64(v);
+            assert_eq!(buffer.read_varint_u64(), Ok(v));
+        }
+        for v in [0i64, -1, 150, -150, i64::MIN, i64::MAX] {
+            buffer.write_varint_i64(v);
+            assert_eq!(buffer.read_varint_i64(), Ok(v));
+        }
+    }
+
+    #[test]
+    fn varint_unexpected_eof() {
+        let mut buffer = Buffer::new();
+        buffer.push(0x80);
+        assert_eq!(buffer.read_varint_u64(), Err(VarintError::UnexpectedEof));
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn bytes_buf_and_buf_mut() {
+        use bytes::{Buf, BufMut};
+
+        let mut buffer = Buffer::from(REFDATA);
+        assert_eq!(buffer.remaining(), REFDATA.len());
+        assert_eq!(buffer.chunk(), REFDATA.as_slice());
+        buffer.advance(2);
+        assert_eq!(buffer.chunk(), &REFDATA[2..]);
+
+        let mut buffer = Buffer::new();
+        buffer.put_slice(REFDATA.as_slice());
+        assert_eq!(&buffer, REFDATA.as_slice());
+    }
+
+    #[test]
+    fn embedded_io_write() {
+        use crate::io::Write;
+
+        let mut buffer = Buffer::new();
+        Write::write(&mut buffer, REFDATA.as_slice()).unwrap();
+        assert_eq!(&buffer, REFDATA.as_slice());
+    }
 }